@@ -1,5 +1,5 @@
 use crate::simple::OffsetParams;
-use crate::{Offsetable, Pageable, DEFAULT_MAX_PAGE_SIZE, DEFAULT_PAGE_SIZE};
+use crate::{Offsetable, Pageable, PaginationError, DEFAULT_MAX_PAGE_SIZE, DEFAULT_PAGE_SIZE};
 
 /// Page Query Condition
 ///
@@ -129,6 +129,27 @@ impl<T> crate::DefaultIntoOffset for PageRequest<T> {
     }
 }
 
+impl<T> crate::TryIntoOffset for PageRequest<T> {
+    type Offset = OffsetRequest<T>;
+
+    fn try_into_offset(
+        self,
+        default_page_size: u32,
+        max_page_size: u32,
+    ) -> Result<Self::Offset, PaginationError> {
+        if self.page_number == 0 {
+            return Err(PaginationError::InvalidPageNumber);
+        }
+        let page_size = crate::simple::try_build_page_size(&self, default_page_size, max_page_size)?;
+        let offset = ((self.page_number as u64) - 1) * (page_size as u64);
+        Ok(OffsetRequest {
+            offset,
+            limit: page_size,
+            request: self.request,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::PageRequest;
@@ -164,4 +185,45 @@ mod tests {
         assert_eq!(offset_req.limit(), 20);
         assert_eq!(offset_req.request().unwrap(), &10_isize);
     }
+
+    #[test]
+    pub fn test_try_into_offset_ok() {
+        use crate::TryIntoOffset;
+
+        let page_request = PageRequest::new(5, 20, 10_isize);
+        let offset_req = page_request.try_into_offset(0, 40).unwrap();
+        assert_eq!(offset_req.offset(), 80_u64);
+        assert_eq!(offset_req.limit(), 20);
+        assert_eq!(offset_req.request().unwrap(), &10_isize);
+    }
+
+    #[test]
+    pub fn test_try_into_offset_page_size_too_large() {
+        use crate::{PaginationError, TryIntoOffset};
+
+        let page_request = PageRequest::new(1, 50, 10_isize);
+        let err = match page_request.try_into_offset(0, 40) {
+            Err(err) => err,
+            Ok(_) => panic!("expected PaginationError::PageSizeTooLarge"),
+        };
+        assert_eq!(
+            err,
+            PaginationError::PageSizeTooLarge {
+                requested: 50,
+                max: 40
+            }
+        );
+    }
+
+    #[test]
+    pub fn test_try_into_offset_invalid_page_number() {
+        use crate::{PaginationError, TryIntoOffset};
+
+        let page_request = PageRequest::new(0, 20, 10_isize);
+        let err = match page_request.try_into_offset(0, 40) {
+            Err(err) => err,
+            Ok(_) => panic!("expected PaginationError::InvalidPageNumber"),
+        };
+        assert_eq!(err, PaginationError::InvalidPageNumber);
+    }
 }
@@ -0,0 +1,370 @@
+use crate::simple::clamp_page_size;
+use std::fmt;
+
+/// Cursor (keyset) 分页查询参数
+///
+/// 与 `PageParams` 对应，但使用 `after` + `first` 的方式描述查询：从游标 `after` 之后
+/// 取 `first` 条记录。相比偏移量分页，不会因为数据插入/删除而产生"串页"，也没有深分页
+/// 的性能问题。
+///
+/// # Example
+/// ``` rust
+/// use pagination::CursorParams;
+///
+/// CursorParams::new(None, 20);
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct CursorParams {
+    after: Option<String>,
+    first: u32,
+}
+
+impl CursorParams {
+    /// # Arguments
+    /// * after 游标，上一页最后一条记录对应的游标；首页传 `None`
+    /// * first 期望获取的条数
+    pub fn new(after: Option<String>, first: u32) -> CursorParams {
+        CursorParams { after, first }
+    }
+
+    /// 游标
+    pub fn after(&self) -> Option<&str> {
+        self.after.as_deref()
+    }
+
+    /// 期望获取的条数
+    pub fn first(&self) -> u32 {
+        self.first
+    }
+
+    /// 按照与 `build_page_size` 相同的规则裁剪 `first`。
+    pub fn resolve(self, default_page_size: u32, max_page_size: u32) -> CursorParams {
+        CursorParams {
+            after: self.after,
+            first: clamp_page_size(self.first, default_page_size, max_page_size),
+        }
+    }
+
+    /// 实际查询时应当获取的条数：多取一条，用来判断 `has_next_page`。
+    pub fn fetch_limit(&self) -> u32 {
+        self.first.saturating_add(1)
+    }
+}
+
+/// Cursor Page Query Condition
+///
+/// 与 `PageRequest` 对应，携带与分页查询无关的其他查询参数。
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct CursorRequest<T: Sized = ()> {
+    after: Option<String>,
+    first: u32,
+    request: Option<T>,
+}
+
+impl<T: Sized> CursorRequest<T> {
+    /// # Arguments
+    /// * after 游标，上一页最后一条记录对应的游标；首页传 `None`
+    /// * first 期望获取的条数
+    /// * request 与分页查询无关的其他查询参数。
+    pub fn new<E>(after: Option<String>, first: u32, request: E) -> CursorRequest<T>
+    where
+        E: Into<Option<T>>,
+    {
+        CursorRequest {
+            after,
+            first,
+            request: request.into(),
+        }
+    }
+
+    /// 游标
+    pub fn after(&self) -> Option<&str> {
+        self.after.as_deref()
+    }
+
+    /// 期望获取的条数
+    pub fn first(&self) -> u32 {
+        self.first
+    }
+
+    /// 与分页查询无关的其他查询参数。
+    pub fn request(&self) -> Option<&T> {
+        self.request.as_ref()
+    }
+
+    /// 按照与 `build_page_size` 相同的规则裁剪 `first`。
+    pub fn resolve(self, default_page_size: u32, max_page_size: u32) -> CursorRequest<T> {
+        CursorRequest {
+            after: self.after,
+            first: clamp_page_size(self.first, default_page_size, max_page_size),
+            request: self.request,
+        }
+    }
+
+    /// 实际查询时应当获取的条数：多取一条，用来判断 `has_next_page`。
+    pub fn fetch_limit(&self) -> u32 {
+        self.first.saturating_add(1)
+    }
+}
+
+/// 记录需要暴露其排序键（用作游标）的字节表示，`CursorPage` 据此生成
+/// `start_cursor` / `end_cursor`。
+pub trait CursorKey {
+    /// 该记录在排序中使用的键的字节表示，例如自增 `id` 的大端字节序
+    fn cursor_key(&self) -> Vec<u8>;
+}
+
+/// 游标分页的导航信息
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct PageInfo {
+    has_next_page: bool,
+    has_previous_page: bool,
+    start_cursor: Option<String>,
+    end_cursor: Option<String>,
+}
+
+impl PageInfo {
+    /// 是否还有下一页
+    pub fn has_next_page(&self) -> bool {
+        self.has_next_page
+    }
+
+    /// 是否还有上一页
+    pub fn has_previous_page(&self) -> bool {
+        self.has_previous_page
+    }
+
+    /// 当前页第一条记录的游标
+    pub fn start_cursor(&self) -> Option<&str> {
+        self.start_cursor.as_deref()
+    }
+
+    /// 当前页最后一条记录的游标
+    pub fn end_cursor(&self) -> Option<&str> {
+        self.end_cursor.as_deref()
+    }
+}
+
+/// Cursor Page Query Result Model
+///
+/// # Example
+/// ``` rust
+/// use pagination::{CursorKey, CursorPage};
+///
+/// struct Row(u64);
+/// impl CursorKey for Row {
+///     fn cursor_key(&self) -> Vec<u8> {
+///         self.0.to_be_bytes().to_vec()
+///     }
+/// }
+///
+/// // 查询时多取了一条 (first = 2, fetch_limit = 3)，说明还有下一页
+/// let page = CursorPage::from_fetched(vec![Row(1), Row(2), Row(3)], 2, false);
+/// assert_eq!(page.records().len(), 2);
+/// assert!(page.page_info().has_next_page());
+/// ```
+#[derive(Clone, Debug)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub struct CursorPage<T> {
+    records: Vec<T>,
+    page_info: PageInfo,
+}
+
+impl<T: CursorKey> CursorPage<T> {
+    /// 用查询返回的记录构造 `CursorPage`。
+    ///
+    /// `rows` 的长度可能是 `requested_first + 1`（参见 `fetch_limit`），多出的一行仅用于
+    /// 判断 `has_next_page`，会被裁剪掉不会出现在 `records` 里。`has_previous_page` 由调用方
+    /// 传入，通常就是请求中 `after.is_some()`。
+    pub fn from_fetched(mut rows: Vec<T>, requested_first: u32, has_previous_page: bool) -> CursorPage<T> {
+        let has_next_page = rows.len() as u32 > requested_first;
+        if has_next_page {
+            rows.truncate(requested_first as usize);
+        }
+        let start_cursor = rows.first().map(|r| encode_cursor(&r.cursor_key()));
+        let end_cursor = rows.last().map(|r| encode_cursor(&r.cursor_key()));
+        CursorPage {
+            records: rows,
+            page_info: PageInfo {
+                has_next_page,
+                has_previous_page,
+                start_cursor,
+                end_cursor,
+            },
+        }
+    }
+}
+
+impl<T> CursorPage<T> {
+    /// 当前页的记录
+    pub fn records(&self) -> &Vec<T> {
+        &self.records
+    }
+
+    /// 导航信息
+    pub fn page_info(&self) -> &PageInfo {
+        &self.page_info
+    }
+}
+
+/// 游标解析错误
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CursorError {
+    /// 游标不是合法的 base64 编码
+    InvalidCursor,
+}
+
+impl fmt::Display for CursorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CursorError::InvalidCursor => write!(f, "cursor is not valid base64"),
+        }
+    }
+}
+
+impl std::error::Error for CursorError {}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// 将排序键字节编码成不透明的游标字符串，供客户端原样传回。
+pub fn encode_cursor(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let b0 = chunk[0] as u32;
+        let b1 = *chunk.get(1).unwrap_or(&0) as u32;
+        let b2 = *chunk.get(2).unwrap_or(&0) as u32;
+        let n = (b0 << 16) | (b1 << 8) | b2;
+        out.push(BASE64_ALPHABET[(n >> 18 & 0x3f) as usize] as char);
+        out.push(BASE64_ALPHABET[(n >> 12 & 0x3f) as usize] as char);
+        out.push(if chunk.len() > 1 {
+            BASE64_ALPHABET[(n >> 6 & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+        out.push(if chunk.len() > 2 {
+            BASE64_ALPHABET[(n & 0x3f) as usize] as char
+        } else {
+            '='
+        });
+    }
+    out
+}
+
+/// 将 `encode_cursor` 生成的游标解码回排序键字节。
+pub fn decode_cursor(cursor: &str) -> Result<Vec<u8>, CursorError> {
+    fn value(c: u8) -> Option<u32> {
+        match c {
+            b'A'..=b'Z' => Some((c - b'A') as u32),
+            b'a'..=b'z' => Some((c - b'a') as u32 + 26),
+            b'0'..=b'9' => Some((c - b'0') as u32 + 52),
+            b'+' => Some(62),
+            b'/' => Some(63),
+            _ => None,
+        }
+    }
+
+    let bytes = cursor.as_bytes();
+    if !bytes.len().is_multiple_of(4) {
+        return Err(CursorError::InvalidCursor);
+    }
+
+    let chunk_count = bytes.len() / 4;
+    let mut out = Vec::with_capacity(chunk_count * 3);
+    for (i, chunk) in bytes.chunks(4).enumerate() {
+        let pad = chunk.iter().filter(|&&c| c == b'=').count();
+        // `=` 只能出现在最后一个分组末尾，且最多 2 个（3 字节编码成 4 字符，最后一组最多缺 2 字节）。
+        if pad > 0 && (i != chunk_count - 1 || pad > 2 || !chunk[4 - pad..].iter().all(|&c| c == b'=')) {
+            return Err(CursorError::InvalidCursor);
+        }
+        let mut n: u32 = 0;
+        for &c in chunk {
+            n <<= 6;
+            if c != b'=' {
+                n |= value(c).ok_or(CursorError::InvalidCursor)?;
+            }
+        }
+        let b = n.to_be_bytes();
+        out.push(b[1]);
+        if pad < 2 {
+            out.push(b[2]);
+        }
+        if pad < 1 {
+            out.push(b[3]);
+        }
+    }
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Row(u64);
+    impl CursorKey for Row {
+        fn cursor_key(&self) -> Vec<u8> {
+            self.0.to_be_bytes().to_vec()
+        }
+    }
+
+    #[test]
+    fn test_fetch_limit() {
+        let params = CursorParams::new(None, 20);
+        assert_eq!(params.fetch_limit(), 21);
+    }
+
+    #[test]
+    fn test_resolve_clamps_first() {
+        let params = CursorParams::new(None, 300).resolve(0, 0);
+        assert_eq!(params.first(), 100);
+    }
+
+    #[test]
+    fn test_from_fetched_trims_sentinel_row() {
+        let rows = vec![Row(1), Row(2), Row(3)];
+        let page = CursorPage::from_fetched(rows, 2, false);
+        assert_eq!(page.records().len(), 2);
+        assert!(page.page_info().has_next_page());
+        assert!(!page.page_info().has_previous_page());
+        assert!(page.page_info().start_cursor().is_some());
+        assert_eq!(page.page_info().end_cursor(), page.page_info().start_cursor().map(|_| encode_cursor(&2u64.to_be_bytes())).as_deref());
+    }
+
+    #[test]
+    fn test_from_fetched_no_next_page() {
+        let rows = vec![Row(1), Row(2)];
+        let page = CursorPage::from_fetched(rows, 2, true);
+        assert_eq!(page.records().len(), 2);
+        assert!(!page.page_info().has_next_page());
+        assert!(page.page_info().has_previous_page());
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let bytes = 12345u64.to_be_bytes().to_vec();
+        let cursor = encode_cursor(&bytes);
+        let decoded = decode_cursor(&cursor).unwrap();
+        assert_eq!(decoded, bytes);
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip_empty() {
+        let cursor = encode_cursor(&[]);
+        assert_eq!(cursor, "");
+        assert_eq!(decode_cursor(&cursor), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_decode_invalid_cursor() {
+        assert_eq!(decode_cursor("not base64!"), Err(CursorError::InvalidCursor));
+    }
+
+    #[test]
+    fn test_decode_rejects_misplaced_padding() {
+        assert_eq!(decode_cursor("A=BC"), Err(CursorError::InvalidCursor));
+        assert_eq!(decode_cursor("AAAA===="), Err(CursorError::InvalidCursor));
+    }
+}
@@ -0,0 +1,31 @@
+use std::fmt;
+
+/// 严格模式下分页参数的校验错误，参见 `TryIntoOffset`。
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "with-serde", derive(Serialize, Deserialize))]
+pub enum PaginationError {
+    /// 请求的每页条数超过了允许的最大值
+    PageSizeTooLarge {
+        /// 请求的每页条数
+        requested: u32,
+        /// 允许的最大每页条数
+        max: u32,
+    },
+    /// 页码无效，页码必须从 1 开始
+    InvalidPageNumber,
+}
+
+impl fmt::Display for PaginationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PaginationError::PageSizeTooLarge { requested, max } => write!(
+                f,
+                "requested page size {} exceeds maximum allowed {}",
+                requested, max
+            ),
+            PaginationError::InvalidPageNumber => write!(f, "page number must start from 1"),
+        }
+    }
+}
+
+impl std::error::Error for PaginationError {}
@@ -20,6 +20,9 @@
 extern crate serde_derive;
 
 mod composite;
+mod cursor;
+mod error;
+mod memory;
 mod page;
 mod simple;
 
@@ -83,8 +86,30 @@ pub trait DefaultIntoOffset {
     fn into_offset(self) -> Self::Offset;
 }
 
+/// 由分页查询参数计算出的基于偏移量的查询，严格模式。
+///
+/// 与 `IntoOffset` 的裁剪行为不同，当请求的页面参数超出限制时返回 `PaginationError`，
+/// 而不是静默裁剪，便于服务端返回 400 之类的错误给客户端。
+pub trait TryIntoOffset {
+    /// see `Offsetable`
+    type Offset: Offsetable + Sized;
+
+    /// 转换成基于偏移量的查询；请求的页面参数不合法时返回错误。
+    fn try_into_offset(
+        self,
+        default_page_size: u32,
+        max_page_size: u32,
+    ) -> Result<Self::Offset, PaginationError>;
+}
+
 pub use composite::OffsetRequest;
 pub use composite::PageRequest;
+pub use cursor::{
+    decode_cursor, encode_cursor, CursorError, CursorKey, CursorPage, CursorParams,
+    CursorRequest, PageInfo,
+};
+pub use error::PaginationError;
+pub use memory::{paginate, paginate_ref, paginate_streaming};
 pub use page::Page;
 pub use simple::OffsetParams;
 pub use simple::PageParams;
@@ -0,0 +1,102 @@
+use crate::{Offsetable, Page};
+
+/// 对内存中的集合（如 `Vec`、切片）按照 `Offsetable` 提供的偏移量/条数分页，返回一个
+/// 携带分页上下文的 `Page`。
+///
+/// 要求 `I::IntoIter: ExactSizeIterator`，因此 `total` 可以在不遍历整个集合的情况下直接取得；
+/// 对于长度未知的迭代器，使用 `paginate_streaming`。
+///
+/// # Example
+/// ``` rust
+/// use pagination::{paginate, IntoOffset, PageParams};
+///
+/// let items = vec![1, 2, 3, 4, 5];
+/// let offset = PageParams::new(2, 2).into_offset(0, 0);
+/// let page = paginate(items, &offset);
+/// assert_eq!(page.records(), &vec![3, 4]);
+/// assert_eq!(page.total(), 5);
+/// ```
+pub fn paginate<I, O>(items: I, req: &O) -> Page<I::Item>
+where
+    I: IntoIterator,
+    I::IntoIter: ExactSizeIterator,
+    O: Offsetable,
+{
+    let iter = items.into_iter();
+    let total = iter.len() as u64;
+    let records: Vec<I::Item> = iter
+        .skip(req.offset() as usize)
+        .take(req.limit() as usize)
+        .collect();
+    Page::with_context(records, total, req.offset(), req.limit())
+}
+
+/// `paginate` 的借用版本，适用于不想消费原集合的场景，例如对 `&[T]` 分页。
+pub fn paginate_ref<'a, T, O>(items: &'a [T], req: &O) -> Page<&'a T>
+where
+    O: Offsetable,
+{
+    let total = items.len() as u64;
+    let records: Vec<&T> = items
+        .iter()
+        .skip(req.offset() as usize)
+        .take(req.limit() as usize)
+        .collect();
+    Page::with_context(records, total, req.offset(), req.limit())
+}
+
+/// 对任意迭代器分页，消费整个迭代器以统计 `total`。
+///
+/// 适用于无法提前知道长度（非 `ExactSizeIterator`）的数据源，例如链式 `filter` 之后的迭代器。
+pub fn paginate_streaming<I, O>(items: I, req: &O) -> Page<I::Item>
+where
+    I: IntoIterator,
+    O: Offsetable,
+{
+    let offset = req.offset();
+    let limit = req.limit() as u64;
+    let mut total = 0u64;
+    let mut records = Vec::new();
+    for item in items {
+        if total >= offset && total < offset + limit {
+            records.push(item);
+        }
+        total += 1;
+    }
+    Page::with_context(records, total, offset, req.limit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{IntoOffset, PageParams};
+
+    #[test]
+    fn test_paginate_exact() {
+        let items = vec![1, 2, 3, 4, 5, 6, 7];
+        let req = PageParams::new(2, 3).into_offset(0, 0);
+        let page = paginate(items, &req);
+        assert_eq!(page.records(), &vec![4, 5, 6]);
+        assert_eq!(page.total(), 7);
+        assert_eq!(page.pages(), 3);
+        assert_eq!(page.current(), 2);
+    }
+
+    #[test]
+    fn test_paginate_ref() {
+        let items = vec![1, 2, 3, 4, 5];
+        let req = PageParams::new(1, 2).into_offset(0, 0);
+        let page = paginate_ref(&items, &req);
+        assert_eq!(page.records(), &vec![&1, &2]);
+        assert_eq!(page.total(), 5);
+    }
+
+    #[test]
+    fn test_paginate_streaming() {
+        let items = (1..=10).filter(|n| n % 2 == 0);
+        let req = PageParams::new(2, 2).into_offset(0, 0);
+        let page = paginate_streaming(items, &req);
+        assert_eq!(page.records(), &vec![6, 8]);
+        assert_eq!(page.total(), 5);
+    }
+}
@@ -15,6 +15,8 @@ use std::ops::Index;
 pub struct Page<T> {
     records: Vec<T>,
     total: u64,
+    offset: u64,
+    limit: u32,
 }
 
 impl<T> Page<T> {
@@ -22,7 +24,29 @@ impl<T> Page<T> {
     /// * records 当前页的记录数
     /// * total 总记录数
     pub fn new(records: Vec<T>, total: u64) -> Page<T> {
-        Page { records, total }
+        Page {
+            records,
+            total,
+            offset: 0,
+            limit: 0,
+        }
+    }
+
+    /// 携带分页上下文（偏移量、每页条数）构造 `Page`，使 `pages`/`current`/`has_next` 等
+    /// 导航信息可以直接从结果中派生，而不需要调用方重新计算。
+    ///
+    /// # Arguments
+    /// * records 当前页的记录数
+    /// * total 总记录数
+    /// * offset 本次查询使用的偏移量，参见 `Offsetable::offset`
+    /// * limit 本次查询使用的每页条数，参见 `Offsetable::limit`
+    pub fn with_context(records: Vec<T>, total: u64, offset: u64, limit: u32) -> Page<T> {
+        Page {
+            records,
+            total,
+            offset,
+            limit,
+        }
     }
 
     pub fn total(&self) -> u64 {
@@ -37,6 +61,75 @@ impl<T> Page<T> {
     pub fn records(&self) -> &Vec<T> {
         &self.records
     }
+
+    /// 本次查询使用的偏移量
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+
+    /// 总页数
+    pub fn pages(&self) -> u64 {
+        if self.limit == 0 {
+            0
+        } else {
+            self.total.div_ceil(self.limit as u64)
+        }
+    }
+
+    /// 当前页码，从 1 开始
+    pub fn current(&self) -> u32 {
+        if self.limit == 0 {
+            1
+        } else {
+            (self.offset / self.limit as u64) as u32 + 1
+        }
+    }
+
+    /// 是否还有下一页
+    pub fn has_next(&self) -> bool {
+        (self.current() as u64) < self.pages()
+    }
+
+    /// 是否还有上一页
+    pub fn has_previous(&self) -> bool {
+        self.current() > 1
+    }
+
+    /// 是否是第一页
+    pub fn is_first(&self) -> bool {
+        self.current() == 1
+    }
+
+    /// 是否是最后一页
+    pub fn is_last(&self) -> bool {
+        !self.has_next()
+    }
+
+    /// 将每条记录映射成另一个类型，`total` 及分页上下文保持不变。
+    ///
+    /// 常用于把数据库实体转换成对外的 DTO。
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> Page<U> {
+        Page {
+            records: self.records.into_iter().map(f).collect(),
+            total: self.total,
+            offset: self.offset,
+            limit: self.limit,
+        }
+    }
+
+    /// `map` 的可失败版本，只要有一条记录转换失败就会返回 `Err`。
+    pub fn try_map<U, E, F: FnMut(T) -> Result<U, E>>(self, mut f: F) -> Result<Page<U>, E> {
+        let mut records = Vec::with_capacity(self.records.len());
+        for record in self.records {
+            records.push(f(record)?);
+        }
+        Ok(Page {
+            records,
+            total: self.total,
+            offset: self.offset,
+            limit: self.limit,
+        })
+    }
 }
 
 impl<T> IntoIterator for Page<T> {
@@ -79,4 +172,53 @@ mod tests {
         let json = to_string(&page);
         assert!(json.is_ok());
     }
+
+    #[test]
+    fn test_new_has_no_navigation_context() {
+        let page = Page::new(vec![10, 20, 30], 3);
+        assert_eq!(page.pages(), 0);
+        assert_eq!(page.current(), 1);
+        assert!(!page.has_next());
+        assert!(!page.has_previous());
+    }
+
+    #[test]
+    fn test_with_context_derives_navigation() {
+        let page = Page::with_context(vec![21, 22, 23, 24, 25], 23, 20, 5);
+        assert_eq!(page.pages(), 5);
+        assert_eq!(page.current(), 5);
+        assert!(!page.has_next());
+        assert!(page.has_previous());
+        assert!(page.is_last());
+        assert!(!page.is_first());
+
+        let first_page = Page::with_context(vec![1, 2, 3, 4, 5], 23, 0, 5);
+        assert_eq!(first_page.current(), 1);
+        assert!(first_page.has_next());
+        assert!(!first_page.has_previous());
+        assert!(first_page.is_first());
+    }
+
+    #[test]
+    fn test_map_preserves_total_and_context() {
+        let page = Page::with_context(vec![1, 2, 3], 10, 0, 3);
+        let mapped = page.map(|n| n.to_string());
+        assert_eq!(mapped.records(), &vec!["1".to_string(), "2".to_string(), "3".to_string()]);
+        assert_eq!(mapped.total(), 10);
+        assert_eq!(mapped.pages(), 4);
+    }
+
+    #[test]
+    fn test_try_map_ok() {
+        let page = Page::new(vec!["1", "2", "3"], 3);
+        let mapped = page.try_map(|s| s.parse::<i32>());
+        assert_eq!(mapped.unwrap().records(), &vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_try_map_err() {
+        let page = Page::new(vec!["1", "x", "3"], 3);
+        let mapped = page.try_map(|s| s.parse::<i32>());
+        assert!(mapped.is_err());
+    }
 }
\ No newline at end of file
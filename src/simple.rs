@@ -83,7 +83,16 @@ impl OffsetParams {
 
 #[inline(always)]
 fn build_page_size<T: Pageable>(pageable: &T, default_size: u32, max_size: u32) -> u32 {
-    match pageable.page_size() {
+    clamp_page_size(pageable.page_size(), default_size, max_size)
+}
+
+/// 按照分页条数的裁剪规则计算每页条数：请求为 0 时取 `default_size`（为 0 时取 `DEFAULT_PAGE_SIZE`），
+/// 否则裁剪到不超过 `max_size`（为 0 时以 `DEFAULT_MAX_PAGE_SIZE` 为上限）。
+///
+/// 供 `build_page_size` 以及其他需要同样裁剪规则的分页模型（如 cursor 分页）复用。
+#[inline(always)]
+pub(crate) fn clamp_page_size(requested: u32, default_size: u32, max_size: u32) -> u32 {
+    match requested {
         0 if default_size > 0 => default_size,
         0 => DEFAULT_PAGE_SIZE,
         x if max_size == 0 => min(DEFAULT_MAX_PAGE_SIZE, x),
@@ -118,6 +127,57 @@ impl crate::DefaultIntoOffset for PageParams {
     }
 }
 
+/// 严格模式下裁剪每页条数：超过 `max_size` 时返回错误，而不是静默裁剪。
+#[inline(always)]
+pub(crate) fn try_build_page_size<T: Pageable>(
+    pageable: &T,
+    default_size: u32,
+    max_size: u32,
+) -> Result<u32, crate::PaginationError> {
+    let requested = pageable.page_size();
+    if requested == 0 {
+        return Ok(if default_size > 0 {
+            default_size
+        } else {
+            DEFAULT_PAGE_SIZE
+        });
+    }
+    let max = if max_size == 0 {
+        DEFAULT_MAX_PAGE_SIZE
+    } else {
+        max_size
+    };
+    if requested > max {
+        Err(crate::PaginationError::PageSizeTooLarge {
+            requested,
+            max,
+        })
+    } else {
+        Ok(requested)
+    }
+}
+
+impl crate::TryIntoOffset for PageParams {
+    type Offset = OffsetParams;
+
+    /// 获取分页查询时,由分页查询参数计算出的便宜量值；页码或每页条数不合法时返回错误。
+    fn try_into_offset(
+        self,
+        default_page_size: u32,
+        max_page_size: u32,
+    ) -> Result<OffsetParams, crate::PaginationError> {
+        if self.page_number == 0 {
+            return Err(crate::PaginationError::InvalidPageNumber);
+        }
+        let page_size = try_build_page_size(&self, default_page_size, max_page_size)?;
+        let offset = ((self.page_number as u64) - 1) * (page_size as u64);
+        Ok(OffsetParams {
+            offset,
+            limit: page_size,
+        })
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -205,4 +265,35 @@ mod tests {
         assert_eq!(offset_param.offset(), 90);
         assert_eq!(offset_param.limit(), 15);
     }
+
+    #[test]
+    pub fn test_try_into_offset_ok() {
+        use crate::TryIntoOffset;
+        let page_param = PageParams::new(5, 0);
+        let offset_param = page_param.try_into_offset(7, 15).unwrap();
+        assert_eq!(offset_param.offset(), 28);
+        assert_eq!(offset_param.limit(), 7);
+    }
+
+    #[test]
+    pub fn test_try_into_offset_page_size_too_large() {
+        use crate::{PaginationError, TryIntoOffset};
+        let page_param = PageParams::new(1, 50);
+        let err = page_param.try_into_offset(7, 15).unwrap_err();
+        assert_eq!(
+            err,
+            PaginationError::PageSizeTooLarge {
+                requested: 50,
+                max: 15
+            }
+        );
+    }
+
+    #[test]
+    pub fn test_try_into_offset_invalid_page_number() {
+        use crate::{PaginationError, TryIntoOffset};
+        let page_param = PageParams::new(0, 10);
+        let err = page_param.try_into_offset(7, 15).unwrap_err();
+        assert_eq!(err, PaginationError::InvalidPageNumber);
+    }
 }